@@ -2,6 +2,9 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 use regex::Regex;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
 
 lazy_static! {
     static ref R_ASAN_HEADLINE: Regex = Regex::new(
@@ -14,14 +17,213 @@ lazy_static! {
     )
     .unwrap();
     static ref R_ASAN_FIRST_FRAME: Regex = Regex::new(r#"#0\s+(?P<frame>0x[a-fA-F0-9]+)"#).unwrap();
+
+    static ref R_MSAN_HEADLINE: Regex = Regex::new(
+        r#"(?P<pid>=+[0-9]+=+)\s*WARNING:\s*MemorySanitizer:\s*(?P<reason>[^\r\n]+)"#
+    )
+    .unwrap();
+
+    static ref R_LSAN_HEADLINE: Regex = Regex::new(
+        r#"(?P<pid>=+[0-9]+=+)\s*ERROR:\s*LeakSanitizer:\s*(?P<reason>[^\r\n]+)"#
+    )
+    .unwrap();
+
+    static ref R_TSAN_HEADLINE: Regex = Regex::new(
+        r#"WARNING:\s*ThreadSanitizer:\s*(?P<reason>[^\r\n(]+?)\s*\(pid=[0-9]+\)"#
+    )
+    .unwrap();
+    static ref R_TSAN_FIRST_FRAME: Regex = Regex::new(
+        r#"#0\s+\S+.*?(?:\+0x(?P<off>[a-fA-F0-9]+)\)|(?P<addr>0x[a-fA-F0-9]+))"#
+    )
+    .unwrap();
+
+    static ref R_UBSAN_HEADLINE: Regex = Regex::new(
+        r#"(?P<file>[^\r\n]+):(?P<line>[0-9]+):(?P<col>[0-9]+):\s*runtime error:\s*(?P<reason>[^\r\n]+)"#
+    )
+    .unwrap();
+
+    static ref R_PID_MARKER: Regex = Regex::new(r#"=+[0-9]+=+"#).unwrap();
+
+    // Symbolized frame lines in either ASan/MSan's `#n 0xADDR in func file:line`
+    // form, or TSan's `#n func file:line (module+0xADDR)` form.
+    static ref R_SYMBOLIZED_FRAME: Regex = Regex::new(
+        r#"#[0-9]+\s+(?:0x[a-fA-F0-9]+\s+in\s+(?P<func>\S+)\s+(?P<file>[^\s:]+):(?P<line>[0-9]+)|(?P<tfunc>\S+)\s+(?P<tfile>[^\s:]+):(?P<tline>[0-9]+)\s+\()"#
+    )
+    .unwrap();
 }
 
+// How many top frames go into a crash signature; enough to distinguish most
+// defects without being so deep that ASLR-irrelevant library frames dominate.
+const SIGNATURE_FRAME_DEPTH: usize = 5;
+
+// Read in chunks this large while streaming a child's output; small enough to
+// keep the rolling pre-headline tail cheap, large enough to avoid excessive
+// syscalls on chatty targets.
+const STREAM_CHUNK_LEN: usize = 8192;
+// How much of the pre-headline output to retain so a headline split across
+// two reads is still matched.
+const STREAM_TAIL_LEN: usize = 256;
+
 #[derive(Debug, PartialEq)]
 pub struct AsanInfo {
     pub stop_reason: String,
     pub operation: String,
     pub first_frame: u64,
     pub body: String,
+    /// A stable hash of the stop reason and top symbolized frames (for UBSan,
+    /// also the diagnostic's `file:line:col`), for bucketing crashes into
+    /// defect classes across many triage runs.
+    pub signature: String,
+}
+
+/// Which sanitizer produced a [`SanitizerReport`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SanitizerKind {
+    Asan,
+    Tsan,
+    Msan,
+    Ubsan,
+    Lsan,
+}
+
+/// A sanitizer report, normalized to the same shape regardless of which tool
+/// produced it.
+#[derive(Debug, PartialEq)]
+pub enum SanitizerReport {
+    Asan(AsanInfo),
+    Tsan(AsanInfo),
+    Msan(AsanInfo),
+    Ubsan(AsanInfo),
+    Lsan(AsanInfo),
+}
+
+impl SanitizerReport {
+    pub fn kind(&self) -> SanitizerKind {
+        match self {
+            SanitizerReport::Asan(_) => SanitizerKind::Asan,
+            SanitizerReport::Tsan(_) => SanitizerKind::Tsan,
+            SanitizerReport::Msan(_) => SanitizerKind::Msan,
+            SanitizerReport::Ubsan(_) => SanitizerKind::Ubsan,
+            SanitizerReport::Lsan(_) => SanitizerKind::Lsan,
+        }
+    }
+
+    pub fn info(&self) -> &AsanInfo {
+        match self {
+            SanitizerReport::Asan(i)
+            | SanitizerReport::Tsan(i)
+            | SanitizerReport::Msan(i)
+            | SanitizerReport::Ubsan(i)
+            | SanitizerReport::Lsan(i) => i,
+        }
+    }
+}
+
+// ASan, MSan, and LSan all print a `==<pid>==` banner around the report and
+// repeat it verbatim as the terminator (when one is printed at all), so they
+// share this bounding logic. TSan and UBSan use different framing and are
+// handled separately below.
+//
+// Returns the end position of the report and whether it's a real terminator
+// (another `==pid==` marker or a `SUMMARY: ` line) as opposed to just having
+// skipped past the banner's own marker-prefixed lines (e.g. `==N==ERROR: ...`
+// followed by `==N==The signal is caused by ...`) with nothing left to find.
+fn find_pid_bounded_end(input: &str, marker_pos: usize, marker: &str) -> (usize, bool) {
+    let body_large = &input[marker_pos..];
+    // Only advance past lines that are actually newline-terminated: on a
+    // streamed, not-yet-complete buffer the last line may still be missing
+    // its trailing '\n', and treating it as complete would push next_pos
+    // past the end of what's actually been read. Worse, searching for the
+    // next marker past an incomplete line risks re-matching that same
+    // line's own (still-buffering) marker prefix as if it were the
+    // terminator, so bail out as "not yet terminated" instead of guessing.
+    let mut next_pos = marker_pos;
+    for line in body_large.split_inclusive('\n') {
+        if !line.ends_with('\n') {
+            return (next_pos, false);
+        }
+        if line.find(marker).is_none() {
+            break;
+        }
+        next_pos += line.len();
+    }
+
+    // This is not perfectly reliable. For instance, if ASAN_OPTIONS="halt_on_error=0"
+    // then there will be no terminating ==1234==ABORTING token.
+    // In that case the only safe option is to eat the rest of the string
+    // Sanitizers really need machine readable output
+    if let Some(pos_rel) = input[next_pos..].find(marker) {
+        (pos_rel + next_pos + marker.len(), true)
+    } else if let Some(pos_rel) = input[next_pos..].find("SUMMARY: ") {
+        let pos = pos_rel + next_pos;
+        let skip_len = input[pos..].find('\n').unwrap_or(0);
+        (pos + skip_len, true)
+    } else {
+        // no match otherwise
+        (input.len(), false)
+    }
+}
+
+fn extract_pid_bounded_body<'a>(input: &'a str, raw_start: usize, marker_pos: usize, marker: &str) -> &'a str {
+    let (end_pos, _) = find_pid_bounded_end(input, marker_pos, marker);
+
+    &input[raw_start..end_pos]
+}
+
+// TSan and UBSan have no `==pid==` banner to key off of, so bound them by the
+// first of a small set of known terminator substrings instead.
+fn extract_until<'a>(input: &'a str, start_pos: usize, terminators: &[&str]) -> &'a str {
+    let rest = &input[start_pos..];
+    let end_rel = terminators
+        .iter()
+        .filter_map(|t| rest.find(t).map(|pos| pos + t.len()))
+        .min()
+        .unwrap_or_else(|| rest.len());
+
+    &input[start_pos..start_pos + end_rel]
+}
+
+// Addresses are ASLR-dependent and won't line up between runs, so the
+// signature is built from symbolized function names and source locations
+// instead of raw frame addresses or module base offsets.
+fn compute_signature(stop_reason: &str, body: &str) -> String {
+    let frames: Vec<String> = R_SYMBOLIZED_FRAME
+        .captures_iter(body)
+        .take(SIGNATURE_FRAME_DEPTH)
+        .map(|c| {
+            let (func, file, line) = match (c.name("func"), c.name("file"), c.name("line")) {
+                (Some(func), Some(file), Some(line)) => (func.as_str(), file.as_str(), line.as_str()),
+                _ => (
+                    c.name("tfunc").unwrap().as_str(),
+                    c.name("tfile").unwrap().as_str(),
+                    c.name("tline").unwrap().as_str(),
+                ),
+            };
+            format!("{}@{}:{}", func, file, line)
+        })
+        .collect();
+
+    let mut hasher = DefaultHasher::new();
+    stop_reason.hash(&mut hasher);
+    frames.join("|").hash(&mut hasher);
+
+    format!("{:016x}", hasher.finish())
+}
+
+fn first_frame_addr(re: &Regex, body: &str) -> u64 {
+    match re.captures(body) {
+        Some(frame) => {
+            let hex = frame
+                .name("frame")
+                .or_else(|| frame.name("off"))
+                .or_else(|| frame.name("addr"))
+                .unwrap()
+                .as_str();
+            let hex = hex.strip_prefix("0x").unwrap_or(hex);
+            u64::from_str_radix(hex, 16).unwrap_or(0)
+        }
+        None => 0,
+    }
 }
 
 // TODO: support multiple sanitizer reports in successsion
@@ -34,40 +236,17 @@ pub fn asan_post_process(input: &str) -> Option<AsanInfo> {
     let asan_headline = asan_match?;
     let asan_start_marker = asan_headline.name("pid").unwrap().as_str();
 
-    // find the bounds of the ASAN print to capture it raw
     let asan_raw_headline = asan_headline.get(0).unwrap();
     let asan_start_pos = asan_raw_headline.start();
+    let marker_pos = asan_headline.name("pid").unwrap().start();
 
-    let asan_body_large = &input[asan_headline.name("pid").unwrap().start()..];
-    let next_pos = asan_body_large.lines().take_while(|x| x.find(asan_start_marker).is_some()).map(|x| x.len()+1).sum::<usize>() + asan_headline.name("pid").unwrap().start();
-
-    // This is not perfectly reliable. For instance, if ASAN_OPTIONS="halt_on_error=0"
-    // then there will be no terminating ==1234==ABORTING token.
-    // In that case the only safe option is to eat the rest of the string
-    // Sanitizers really need machine readable output
-    let end_pos: usize = if let Some(pos_rel) = &input[next_pos..].find(asan_start_marker) {
-        pos_rel + next_pos + asan_start_marker.len()
-    } else if let Some(pos_rel) = &input[next_pos..].find("SUMMARY: ") {
-        let pos = pos_rel + next_pos;
-        let skip_len = &input[pos..].find("\n").unwrap_or(0);
-        pos + skip_len
-    } else {
-        // no match otherwise
-        next_pos
-    };
-
-    let asan_body = &input[asan_start_pos..end_pos];
+    let asan_body = extract_pid_bounded_body(input, asan_start_pos, marker_pos, asan_start_marker);
 
     let stop_reason = asan_headline.name("reason").unwrap().as_str().to_string();
 
     // Try and find the frame where ASAN was triggered from
     // That way we can print a better info message
-    let asan_first_frame: u64 = match R_ASAN_FIRST_FRAME.captures(asan_body) {
-        Some(frame) => {
-            u64::from_str_radix(&(frame.name("frame").unwrap().as_str())[2..], 16).unwrap()
-        }
-        None => 0,
-    };
+    let asan_first_frame = first_frame_addr(&R_ASAN_FIRST_FRAME, asan_body);
 
     let operation: &str = match asan_headline.name("operation") {
         Some(op) => {
@@ -80,14 +259,225 @@ pub fn asan_post_process(input: &str) -> Option<AsanInfo> {
         _ => "",
     };
 
+    let signature = compute_signature(&stop_reason, asan_body);
+
     Some(AsanInfo {
         stop_reason,
         operation: operation.to_string(),
         first_frame: asan_first_frame,
         body: asan_body.trim_end().to_string(),
+        signature,
+    })
+}
+
+pub fn msan_post_process(input: &str) -> Option<AsanInfo> {
+    let headline = R_MSAN_HEADLINE.captures_iter(input).last()?;
+    let marker = headline.name("pid").unwrap().as_str();
+    let raw_start = headline.get(0).unwrap().start();
+    let marker_pos = headline.name("pid").unwrap().start();
+
+    let body = extract_pid_bounded_body(input, raw_start, marker_pos, marker);
+    let stop_reason = headline.name("reason").unwrap().as_str().trim().to_string();
+    let first_frame = first_frame_addr(&R_ASAN_FIRST_FRAME, body);
+    let signature = compute_signature(&stop_reason, body);
+
+    Some(AsanInfo {
+        stop_reason,
+        operation: "".to_string(),
+        first_frame,
+        body: body.trim_end().to_string(),
+        signature,
+    })
+}
+
+pub fn lsan_post_process(input: &str) -> Option<AsanInfo> {
+    let headline = R_LSAN_HEADLINE.captures_iter(input).last()?;
+    let marker = headline.name("pid").unwrap().as_str();
+    let raw_start = headline.get(0).unwrap().start();
+    let marker_pos = headline.name("pid").unwrap().start();
+
+    let body = extract_pid_bounded_body(input, raw_start, marker_pos, marker);
+    let stop_reason = headline.name("reason").unwrap().as_str().trim().to_string();
+    // Leak reports don't have a single triggering frame, but the first leak's
+    // first frame is still useful for an at-a-glance summary.
+    let first_frame = first_frame_addr(&R_ASAN_FIRST_FRAME, body);
+    let signature = compute_signature(&stop_reason, body);
+
+    Some(AsanInfo {
+        stop_reason,
+        operation: "".to_string(),
+        first_frame,
+        body: body.trim_end().to_string(),
+        signature,
+    })
+}
+
+pub fn tsan_post_process(input: &str) -> Option<AsanInfo> {
+    let headline = R_TSAN_HEADLINE.captures_iter(input).last()?;
+    let start_pos = headline.get(0).unwrap().start();
+
+    let body = extract_until(input, start_pos, &["\n==================", "SUMMARY: ThreadSanitizer:"]);
+    let stop_reason = headline.name("reason").unwrap().as_str().trim().to_string();
+    let first_frame = first_frame_addr(&R_TSAN_FIRST_FRAME, body);
+    let signature = compute_signature(&stop_reason, body);
+
+    Some(AsanInfo {
+        stop_reason,
+        operation: "".to_string(),
+        first_frame,
+        body: body.trim_end().to_string(),
+        signature,
     })
 }
 
+pub fn ubsan_post_process(input: &str) -> Option<AsanInfo> {
+    let headline = R_UBSAN_HEADLINE.captures_iter(input).last()?;
+    let start_pos = headline.get(0).unwrap().start();
+
+    // UBSan rarely prints a stack unless print_stacktrace=1, so there's often
+    // nothing past the one-line diagnostic to bound on.
+    let body = extract_until(input, start_pos, &["\n\n"]);
+    let message = headline.name("reason").unwrap().as_str().trim();
+    let operation = message.split(',').next().unwrap_or(message).trim().to_string();
+    let first_frame = first_frame_addr(&R_ASAN_FIRST_FRAME, body);
+
+    // UBSan reports rarely carry a symbolized stack (see above), so the
+    // file:line:col of the diagnostic itself is the only thing that reliably
+    // distinguishes one UB site from another; fold it into the signature
+    // seed so unrelated sites don't collapse into a single bucket.
+    let location = format!(
+        "{}:{}:{}",
+        headline.name("file").unwrap().as_str(),
+        headline.name("line").unwrap().as_str(),
+        headline.name("col").unwrap().as_str(),
+    );
+    let signature = compute_signature(&format!("undefined-behavior@{}", location), body);
+
+    Some(AsanInfo {
+        stop_reason: "undefined-behavior".to_string(),
+        operation,
+        first_frame,
+        body: body.trim_end().to_string(),
+        signature,
+    })
+}
+
+fn newest_headline_pos(input: &str) -> Option<(usize, SanitizerKind)> {
+    [
+        R_ASAN_HEADLINE.captures_iter(input).last().map(|c| (c.get(0).unwrap().start(), SanitizerKind::Asan)),
+        R_TSAN_HEADLINE.captures_iter(input).last().map(|c| (c.get(0).unwrap().start(), SanitizerKind::Tsan)),
+        R_MSAN_HEADLINE.captures_iter(input).last().map(|c| (c.get(0).unwrap().start(), SanitizerKind::Msan)),
+        R_UBSAN_HEADLINE.captures_iter(input).last().map(|c| (c.get(0).unwrap().start(), SanitizerKind::Ubsan)),
+        R_LSAN_HEADLINE.captures_iter(input).last().map(|c| (c.get(0).unwrap().start(), SanitizerKind::Lsan)),
+    ]
+    .into_iter()
+    .flatten()
+    .max_by_key(|(pos, _)| *pos)
+}
+
+/// Parse the newest sanitizer report in `input`, whichever tool produced it.
+pub fn sanitizer_post_process(input: &str) -> Option<SanitizerReport> {
+    let (_, kind) = newest_headline_pos(input)?;
+
+    match kind {
+        SanitizerKind::Asan => asan_post_process(input).map(SanitizerReport::Asan),
+        SanitizerKind::Tsan => tsan_post_process(input).map(SanitizerReport::Tsan),
+        SanitizerKind::Msan => msan_post_process(input).map(SanitizerReport::Msan),
+        SanitizerKind::Ubsan => ubsan_post_process(input).map(SanitizerReport::Ubsan),
+        SanitizerKind::Lsan => lsan_post_process(input).map(SanitizerReport::Lsan),
+    }
+}
+
+// A report is considered bounded once the terminator `extract_pid_bounded_body`
+// / `extract_until` bound on has been found in what is buffered so far, not
+// merely once some `==pid==` line has reappeared: ASan/MSan/LSan
+// banners routinely print several marker-prefixed lines (`==N==ERROR: ...`,
+// `==N==The signal is caused by ...`, `==N==Hint: ...`) before the stack, so a
+// raw marker count would cut the buffer off before a single frame is captured.
+// A `SUMMARY: ` line ends the stream as soon as it appears, even where a
+// fully-materialized input would go on to a later `==pid==` marker.
+fn has_terminator(buf: &str, headline_start: usize, kind: SanitizerKind) -> bool {
+    match kind {
+        SanitizerKind::Asan | SanitizerKind::Msan | SanitizerKind::Lsan => {
+            match R_PID_MARKER.find(&buf[headline_start..]) {
+                Some(m) => {
+                    let marker_pos = headline_start + m.start();
+                    find_pid_bounded_end(buf, marker_pos, m.as_str()).1
+                }
+                None => false,
+            }
+        }
+        SanitizerKind::Tsan => {
+            let rest = &buf[headline_start..];
+            rest.find("\n==================").is_some() || rest.find("SUMMARY: ThreadSanitizer:").is_some()
+        }
+        SanitizerKind::Ubsan => buf[headline_start..].find("\n\n").is_some(),
+    }
+}
+
+fn trim_to_tail(s: &str, keep: usize) -> String {
+    if s.len() <= keep {
+        return s.to_string();
+    }
+
+    let mut start = s.len() - keep;
+    while !s.is_char_boundary(start) {
+        start += 1;
+    }
+
+    s[start..].to_string()
+}
+
+/// Incrementally extract a sanitizer report from a child's live output.
+///
+/// Unlike [`sanitizer_post_process`], this doesn't require the entire output
+/// to be materialized up front: until a headline is seen, only a small
+/// rolling tail is kept (so a headline split across two reads still matches),
+/// and once one is found only the report itself is buffered. Peak memory use
+/// is proportional to the report size, not the target's total output.
+pub fn extract_streaming<R: Read>(mut reader: R) -> Option<SanitizerReport> {
+    let mut tail = String::new();
+    let mut body: Option<(usize, SanitizerKind, String)> = None;
+    let mut chunk = [0u8; STREAM_CHUNK_LEN];
+
+    loop {
+        let n = reader.read(&mut chunk).unwrap_or(0);
+        if n == 0 {
+            break;
+        }
+
+        let text = String::from_utf8_lossy(&chunk[..n]);
+
+        if let Some((headline_start, kind, buf)) = &mut body {
+            buf.push_str(&text);
+            if has_terminator(buf, *headline_start, *kind) {
+                break;
+            }
+        } else {
+            tail.push_str(&text);
+
+            if let Some((start, kind)) = newest_headline_pos(&tail) {
+                let terminated = has_terminator(&tail, start, kind);
+                body = Some((start, kind, std::mem::take(&mut tail)));
+                if terminated {
+                    break;
+                }
+            } else {
+                tail = trim_to_tail(&tail, STREAM_TAIL_LEN);
+            }
+        }
+    }
+
+    // Either a full, bounded report, or whatever was accumulated before EOF
+    // for truncated / halt_on_error=0-style reports.
+    let accumulated = match body {
+        Some((_, _, buf)) => buf,
+        None if !tail.is_empty() => tail,
+        None => return None,
+    };
+
+    sanitizer_post_process(&accumulated)
+}
 
 #[cfg(test)]
 mod test {
@@ -149,6 +539,133 @@ mod test {
                 operation: "".into(),
                 first_frame: 0,
                 body: m.trim().into(),
+                signature: compute_signature("CODE", m.trim()),
             });
     }
+
+    #[test]
+    fn test_signature_ignores_addresses_but_not_frames() {
+        let with_frame = "#0 0x561010d1d83b in crash_segv /tmp/test.c:14";
+        let same_frame_different_addr = "#0 0xdeadbeef in crash_segv /tmp/test.c:14";
+        let different_frame = "#0 0x561010d1d83b in other_func /tmp/test.c:20";
+
+        assert_eq!(
+            compute_signature("SEGV", with_frame),
+            compute_signature("SEGV", same_frame_different_addr)
+        );
+        assert_ne!(
+            compute_signature("SEGV", with_frame),
+            compute_signature("SEGV", different_frame)
+        );
+
+        // TSan's `#n func file:line (module+0xADDR)` frame form must also
+        // contribute real frames rather than leaving the signature to
+        // degenerate to a hash of the stop reason alone.
+        let tsan_frame = "#0 thread_func /tmp/test_race.c:10 (a.out+0x0000004012a4)";
+        assert_ne!(compute_signature("data race", tsan_frame), compute_signature("data race", ""));
+    }
+
+    #[test]
+    fn test_tsan_report_parsing() {
+        let a = String::from_utf8_lossy(include_bytes!("./sanitizer_reports/tsan_data_race.txt"));
+        let r = tsan_post_process(&a).unwrap();
+
+        assert_eq!(r.stop_reason, "data race");
+
+        let a = String::from_utf8_lossy(include_bytes!("./sanitizer_reports/tsan_thread_leak.txt"));
+        let r = tsan_post_process(&a).unwrap();
+
+        assert_eq!(r.stop_reason, "thread leak");
+    }
+
+    #[test]
+    fn test_msan_report_parsing() {
+        let a = String::from_utf8_lossy(include_bytes!("./sanitizer_reports/msan_uninit.txt"));
+        let r = msan_post_process(&a).unwrap();
+
+        assert_eq!(r.stop_reason, "use-of-uninitialized-value");
+    }
+
+    #[test]
+    fn test_ubsan_report_parsing() {
+        let a = String::from_utf8_lossy(include_bytes!("./sanitizer_reports/ubsan_overflow.txt"));
+        let r = ubsan_post_process(&a).unwrap();
+
+        assert_eq!(r.stop_reason, "undefined-behavior");
+        assert_eq!(r.first_frame, 0);
+
+        // Different UB sites must not collapse into the same bucket just
+        // because they share the generic "undefined-behavior" stop reason.
+        let other = "/tmp/other.c:3:1: runtime error: null pointer dereference\n";
+        let other_r = ubsan_post_process(other).unwrap();
+
+        assert_ne!(r.signature, other_r.signature);
+    }
+
+    #[test]
+    fn test_lsan_report_parsing() {
+        let a = String::from_utf8_lossy(include_bytes!("./sanitizer_reports/lsan_leak.txt"));
+        let r = lsan_post_process(&a).unwrap();
+
+        assert_eq!(r.stop_reason, "detected memory leaks");
+    }
+
+    #[test]
+    fn test_sanitizer_post_process_dispatch() {
+        assert!(sanitizer_post_process("").is_none());
+
+        let a = String::from_utf8_lossy(include_bytes!("./sanitizer_reports/tsan_data_race.txt"));
+        let r = sanitizer_post_process(&a).unwrap();
+
+        assert_eq!(r.kind(), SanitizerKind::Tsan);
+        assert_eq!(r.info().stop_reason, "data race");
+    }
+
+    // A `Read` impl that yields a handful of bytes at a time, so a headline
+    // split across reads actually exercises the rolling tail buffer.
+    struct TinyReader<'a>(&'a [u8]);
+
+    impl<'a> Read for TinyReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = std::cmp::min(4, std::cmp::min(buf.len(), self.0.len()));
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    // A realistic ASan SEGV report prints several ==pid==-prefixed lines
+    // (ERROR, "The signal is caused by...", "Hint: ...") before the stack, so
+    // this is what tripped a naive "second ==pid== line" terminator check.
+    const ASAN_SEGV_WITH_INTERIOR_MARKERS: &str = "\
+==1234==ERROR: AddressSanitizer: SEGV on unknown address 0x000000000000\n\
+==1234==The signal is caused by a READ memory access.\n\
+==1234==Hint: address points to the zero page.\n\
+    #0 0x561010d1d83b in crash_segv /tmp/test.c:14:5\n\
+    #1 0x561010d1d900 in main /tmp/test.c:20:3\n\
+\n\
+SUMMARY: AddressSanitizer: SEGV /tmp/test.c:14 in crash_segv\n\
+==1234==ABORTING\n";
+
+    #[test]
+    fn test_extract_streaming() {
+        let a = include_bytes!("./sanitizer_reports/asan_multi.txt");
+        let r = extract_streaming(TinyReader(a)).unwrap();
+
+        assert_eq!(r.kind(), SanitizerKind::Asan);
+        assert_eq!(r.info().stop_reason, "SEGV");
+
+        assert!(extract_streaming(TinyReader(b"no report here")).is_none());
+    }
+
+    #[test]
+    fn test_extract_streaming_matches_full_parse_with_interior_markers() {
+        let streamed = extract_streaming(TinyReader(ASAN_SEGV_WITH_INTERIOR_MARKERS.as_bytes())).unwrap();
+        let full = sanitizer_post_process(ASAN_SEGV_WITH_INTERIOR_MARKERS).unwrap();
+
+        assert_eq!(streamed.info().first_frame, 0x561010d1d83b);
+        assert_eq!(streamed.info().first_frame, full.info().first_frame);
+        assert_eq!(streamed.info().signature, full.info().signature);
+        assert_ne!(streamed.info().signature, compute_signature("SEGV", ""));
+    }
 }